@@ -0,0 +1,66 @@
+use std::ops::AddAssign;
+
+/// The group/field/pairing operations `PairingBatcher` needs, decoupled from
+/// any one pairing-engine ecosystem so the same batching logic can drive
+/// `ark_ec::pairing::Pairing` implementations (Groth16, PLONK, ...) as well
+/// as the `pairing`/`halo2curves` stack (`MultiMillerLoop`, `G1Prepared`,
+/// `G2Prepared`, `MillerLoopResult`).
+pub trait PairingBackend {
+    /// Scalar field the batching coefficients live in.
+    type Scalar: Copy;
+    /// Affine G1 point, as produced by the caller.
+    type G1Affine: Copy;
+    /// Projective G1 point, accumulated across equations.
+    type G1: Copy + AddAssign;
+    /// Affine G2 point, as produced by the caller. G2 points are grouped
+    /// (never combined) via their [`serialize_g2`](Self::serialize_g2)
+    /// encoding as the map key, since not every ecosystem's affine point
+    /// type is hashable (e.g. `bls12_381::G2Affine` is `Eq` but not
+    /// `Hash`).
+    type G2Affine: Copy;
+    /// G1 point prepared for the Miller loop.
+    type G1Prepared;
+    /// G2 point prepared for the Miller loop.
+    type G2Prepared;
+    /// Output of a (possibly batched) Miller loop, prior to final
+    /// exponentiation.
+    type MillerLoopOutput;
+    /// Target field element a successful pairing check collapses to.
+    type TargetField: Copy + PartialEq;
+
+    fn scalar_zero() -> Self::Scalar;
+    fn scalar_one() -> Self::Scalar;
+    /// Derives a scalar from transcript output bytes.
+    fn scalar_from_bytes(bytes: &[u8]) -> Self::Scalar;
+    fn scalar_add_assign(lhs: &mut Self::Scalar, rhs: Self::Scalar);
+    fn scalar_mul_assign(lhs: &mut Self::Scalar, rhs: Self::Scalar);
+
+    fn g1_into_projective(point: Self::G1Affine) -> Self::G1;
+    fn g1_into_affine(point: Self::G1) -> Self::G1Affine;
+    fn g1_mul_scalar(point: Self::G1Affine, scalar: Self::Scalar) -> Self::G1;
+
+    /// Compressed serialization, fed to the transcript.
+    fn serialize_g1(point: &Self::G1Affine) -> Vec<u8>;
+    /// Compressed serialization, fed to the transcript.
+    fn serialize_g2(point: &Self::G2Affine) -> Vec<u8>;
+
+    fn prepare_g1(point: Self::G1) -> Self::G1Prepared;
+    fn prepare_g2(point: Self::G2Affine) -> Self::G2Prepared;
+
+    fn multi_miller_loop(
+        g1: Vec<Self::G1Prepared>,
+        g2: Vec<Self::G2Prepared>,
+    ) -> Self::MillerLoopOutput;
+    fn final_exponentiation(miller: Self::MillerLoopOutput) -> Option<Self::TargetField>;
+    fn target_one() -> Self::TargetField;
+    /// Raises a target field element to a scalar-field exponent, used to
+    /// fold a precomputed constant (e.g. Groth16's `alpha * beta`) into a
+    /// batched equation without spending a Miller loop term on it.
+    fn target_pow_scalar(base: Self::TargetField, exponent: Self::Scalar) -> Self::TargetField;
+}
+
+mod ark;
+mod zkcrypto;
+
+pub use ark::{ArkBackend, PreparedCache};
+pub use zkcrypto::ZkcryptoBackend;
@@ -0,0 +1,103 @@
+use std::marker::PhantomData;
+
+use ark_ec::pairing::Pairing;
+use ark_ff::{Field, One, PrimeField, Zero};
+use ark_serialize::CanonicalSerialize;
+
+use super::PairingBackend;
+
+/// [`PairingBackend`] wrapping `ark_ec::pairing::Pairing`, i.e. the existing
+/// behavior prior to the backend abstraction.
+pub struct ArkBackend<E>(PhantomData<E>);
+
+impl<E: Pairing> PairingBackend for ArkBackend<E> {
+    type Scalar = E::ScalarField;
+    type G1Affine = E::G1Affine;
+    type G1 = E::G1;
+    type G2Affine = E::G2Affine;
+    type G1Prepared = E::G1Prepared;
+    type G2Prepared = E::G2Prepared;
+    type MillerLoopOutput = ark_ec::pairing::MillerLoopOutput<E>;
+    type TargetField = E::TargetField;
+
+    fn scalar_zero() -> Self::Scalar {
+        E::ScalarField::zero()
+    }
+
+    fn scalar_one() -> Self::Scalar {
+        E::ScalarField::one()
+    }
+
+    fn scalar_from_bytes(bytes: &[u8]) -> Self::Scalar {
+        E::ScalarField::from_le_bytes_mod_order(bytes)
+    }
+
+    fn scalar_add_assign(lhs: &mut Self::Scalar, rhs: Self::Scalar) {
+        *lhs += rhs;
+    }
+
+    fn scalar_mul_assign(lhs: &mut Self::Scalar, rhs: Self::Scalar) {
+        *lhs *= rhs;
+    }
+
+    fn g1_into_projective(point: Self::G1Affine) -> Self::G1 {
+        point.into()
+    }
+
+    fn g1_into_affine(point: Self::G1) -> Self::G1Affine {
+        point.into()
+    }
+
+    fn g1_mul_scalar(point: Self::G1Affine, scalar: Self::Scalar) -> Self::G1 {
+        point * scalar
+    }
+
+    fn serialize_g1(point: &Self::G1Affine) -> Vec<u8> {
+        let mut bytes = vec![];
+        point
+            .serialize_compressed(&mut bytes)
+            .expect("serialization of affine point should not fail");
+        bytes
+    }
+
+    fn serialize_g2(point: &Self::G2Affine) -> Vec<u8> {
+        let mut bytes = vec![];
+        point
+            .serialize_compressed(&mut bytes)
+            .expect("serialization of affine point should not fail");
+        bytes
+    }
+
+    fn prepare_g1(point: Self::G1) -> Self::G1Prepared {
+        let affine: Self::G1Affine = point.into();
+        affine.into()
+    }
+
+    fn prepare_g2(point: Self::G2Affine) -> Self::G2Prepared {
+        point.into()
+    }
+
+    fn multi_miller_loop(
+        g1: Vec<Self::G1Prepared>,
+        g2: Vec<Self::G2Prepared>,
+    ) -> Self::MillerLoopOutput {
+        E::multi_miller_loop(g1, g2)
+    }
+
+    fn final_exponentiation(miller: Self::MillerLoopOutput) -> Option<Self::TargetField> {
+        E::final_exponentiation(miller).map(|output| output.0)
+    }
+
+    fn target_one() -> Self::TargetField {
+        E::TargetField::one()
+    }
+
+    fn target_pow_scalar(base: Self::TargetField, exponent: Self::Scalar) -> Self::TargetField {
+        base.pow(exponent.into_bigint())
+    }
+}
+
+mod line_coeffs;
+mod prepared_cache;
+
+pub use prepared_cache::PreparedCache;
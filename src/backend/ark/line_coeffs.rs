@@ -0,0 +1,27 @@
+//! Batch preparation of G2 points for the Miller loop.
+//!
+//! An earlier version of this module reimplemented the Miller-loop line
+//! function directly in affine coordinates to share denominator inversions
+//! across a batch via Montgomery's trick. That reimplementation diverged
+//! from arkworks' own line-function convention (it ignored
+//! `Bls12Config::TWIST_TYPE`, among other things) and produced incorrect
+//! `G2Prepared` values, so it was dropped rather than shipped broken.
+//!
+//! This now just defers to arkworks' own (correct) per-point conversion -
+//! no inversions are shared across a batch. The only win here is what
+//! [`PreparedCache`](super::PreparedCache) gives on top: a point seen on a
+//! previous `finalize_with_cache` call is never re-prepared. The "share
+//! denominator inversions via Montgomery batch inversion across points
+//! prepared in the same pass" half of the original request is not done;
+//! a correct from-scratch affine Miller loop, checked against arkworks'
+//! own `G2Prepared` (not just against `finalize`'s end-to-end result,
+//! which can coincidentally agree even when the intermediate line
+//! coefficients don't), would need to land before that's true.
+
+use ark_ec::bls12::{Bls12Config, G2Prepared};
+use ark_ec::short_weierstrass::Affine;
+
+/// Prepares each of `points` for the Miller loop.
+pub fn prepare_g2_batch<P: Bls12Config>(points: &[Affine<P::G2Config>]) -> Vec<G2Prepared<P>> {
+    points.iter().map(|&point| point.into()).collect()
+}
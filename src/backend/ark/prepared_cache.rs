@@ -0,0 +1,130 @@
+use std::collections::{HashMap, HashSet};
+
+use ark_ec::bls12::{Bls12, Bls12Config, G2Prepared};
+use ark_ec::pairing::Pairing;
+use ark_ec::short_weierstrass::Affine;
+use ark_ff::One;
+
+use super::line_coeffs::prepare_g2_batch;
+use crate::backend::ArkBackend;
+use crate::{PairingBatcher, Transcript};
+
+/// Caches prepared G2 points (their Miller-loop line-coefficient sequence)
+/// keyed by the affine point itself, so verifying-key elements reused
+/// across many `finalize` calls (e.g. `gamma`, `delta`, `beta`) are only
+/// ever prepared once.
+pub struct PreparedCache<P: Bls12Config> {
+    cache: HashMap<Affine<P::G2Config>, G2Prepared<P>>,
+}
+
+impl<P: Bls12Config> Default for PreparedCache<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: Bls12Config> PreparedCache<P> {
+    pub fn new() -> Self {
+        Self {
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Prepares `points`, consulting (and populating) the cache first so a
+    /// point seen on a previous call is never re-prepared.
+    pub fn prepare_many(&mut self, points: &[Affine<P::G2Config>]) -> Vec<G2Prepared<P>> {
+        let mut seen = HashSet::new();
+        let to_compute: Vec<Affine<P::G2Config>> = points
+            .iter()
+            .copied()
+            .filter(|q| !self.cache.contains_key(q) && seen.insert(*q))
+            .collect();
+
+        if !to_compute.is_empty() {
+            for (point, prepared) in to_compute.iter().zip(prepare_g2_batch::<P>(&to_compute)) {
+                self.cache.insert(*point, prepared);
+            }
+        }
+
+        points
+            .iter()
+            .map(|q| self.cache.get(q).expect("just inserted").clone())
+            .collect()
+    }
+}
+
+impl<P: Bls12Config> PairingBatcher<ArkBackend<Bls12<P>>> {
+    /// Like [`finalize`](PairingBatcher::finalize), but consults `cache`
+    /// for each equation's G2 points instead of re-preparing them from
+    /// scratch.
+    pub fn finalize_with_cache<T: Transcript<ArkBackend<Bls12<P>>>>(
+        &self,
+        transcript: &mut T,
+        cache: &mut PreparedCache<P>,
+    ) -> (
+        Vec<<Bls12<P> as Pairing>::G1Prepared>,
+        Vec<G2Prepared<P>>,
+    ) {
+        let r = self.challenge(transcript);
+
+        let mut g2_to_g1: HashMap<Affine<P::G2Config>, <Bls12<P> as Pairing>::G1> = HashMap::new();
+        let mut running_challenge = <Bls12<P> as Pairing>::ScalarField::one();
+        for equation in &self.equations {
+            for &(g1, g2) in equation {
+                let g1 = g1 * running_challenge;
+                g2_to_g1
+                    .entry(g2)
+                    .and_modify(|acc| *acc += g1)
+                    .or_insert(g1);
+            }
+            running_challenge *= r;
+        }
+
+        let (g2_points, g1_points): (Vec<_>, Vec<_>) = g2_to_g1.into_iter().unzip();
+        let g2_prepared = cache.prepare_many(&g2_points);
+        let g1_prepared = g1_points.into_iter().map(Into::into).collect();
+
+        (g1_prepared, g2_prepared)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ark_bls12_381::{Bls12_381, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+    use ark_ec::pairing::Pairing;
+    use ark_ec::Group;
+    use ark_std::{test_rng, UniformRand};
+
+    use super::PreparedCache;
+    use crate::{ArkBackend, KeccakTranscript, PairingBatcher};
+
+    #[test]
+    fn finalize_with_cache_matches_finalize() {
+        let mut rng = test_rng();
+
+        let a: G1Affine = (G1Projective::generator() * Fr::rand(&mut rng)).into();
+        let b: G2Affine = (G2Projective::generator() * Fr::rand(&mut rng)).into();
+        let c: G1Affine = (G1Projective::generator() * Fr::rand(&mut rng)).into();
+        let d: G2Affine = (G2Projective::generator() * Fr::rand(&mut rng)).into();
+
+        let mut batcher = PairingBatcher::<ArkBackend<Bls12_381>>::new();
+        batcher.add_pairing(&[(a, b), (c, d)]);
+
+        let expected = {
+            let mut transcript = KeccakTranscript::default();
+            let (g1, g2) = batcher.finalize(&mut transcript);
+            let miller = Bls12_381::multi_miller_loop(g1, g2);
+            Bls12_381::final_exponentiation(miller).unwrap().0
+        };
+
+        let actual = {
+            let mut transcript = KeccakTranscript::default();
+            let mut cache = PreparedCache::new();
+            let (g1, g2) = batcher.finalize_with_cache(&mut transcript, &mut cache);
+            let miller = Bls12_381::multi_miller_loop(g1, g2);
+            Bls12_381::final_exponentiation(miller).unwrap().0
+        };
+
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,150 @@
+use std::marker::PhantomData;
+
+use ff::Field;
+use group::{Curve, Group, GroupEncoding};
+use pairing::{MillerLoopResult, MultiMillerLoop};
+
+use super::PairingBackend;
+
+/// [`PairingBackend`] wrapping `pairing::MultiMillerLoop`, the trait the
+/// `bls12_381`/`halo2curves` ecosystem is built around.
+pub struct ZkcryptoBackend<P>(PhantomData<P>);
+
+impl<P> PairingBackend for ZkcryptoBackend<P>
+where
+    P: MultiMillerLoop,
+    P::G1Affine: GroupEncoding,
+    P::G2Affine: GroupEncoding,
+    P::G2Prepared: Clone + From<P::G2Affine>,
+    P::Gt: Group<Scalar = P::Fr> + Copy,
+{
+    type Scalar = P::Fr;
+    type G1Affine = P::G1Affine;
+    type G1 = P::G1;
+    type G2Affine = P::G2Affine;
+    type G1Prepared = P::G1Affine;
+    type G2Prepared = P::G2Prepared;
+    type MillerLoopOutput = P::Result;
+    type TargetField = P::Gt;
+
+    fn scalar_zero() -> Self::Scalar {
+        P::Fr::ZERO
+    }
+
+    fn scalar_one() -> Self::Scalar {
+        P::Fr::ONE
+    }
+
+    fn scalar_from_bytes(bytes: &[u8]) -> Self::Scalar {
+        // `PrimeField::from_repr` rejects any non-canonical representative
+        // instead of reducing it, so folding in bytes bit by bit (doubling
+        // and conditionally adding one) is used to reduce mod the field
+        // order, mirroring `ArkBackend`'s `from_le_bytes_mod_order`.
+        let mut acc = P::Fr::ZERO;
+        for byte in bytes {
+            for i in (0..8).rev() {
+                acc = acc.double();
+                if (byte >> i) & 1 == 1 {
+                    acc += P::Fr::ONE;
+                }
+            }
+        }
+        acc
+    }
+
+    fn scalar_add_assign(lhs: &mut Self::Scalar, rhs: Self::Scalar) {
+        *lhs += rhs;
+    }
+
+    fn scalar_mul_assign(lhs: &mut Self::Scalar, rhs: Self::Scalar) {
+        *lhs *= rhs;
+    }
+
+    fn g1_into_projective(point: Self::G1Affine) -> Self::G1 {
+        point.into()
+    }
+
+    fn g1_into_affine(point: Self::G1) -> Self::G1Affine {
+        point.to_affine()
+    }
+
+    fn g1_mul_scalar(point: Self::G1Affine, scalar: Self::Scalar) -> Self::G1 {
+        let point: Self::G1 = point.into();
+        point * scalar
+    }
+
+    fn serialize_g1(point: &Self::G1Affine) -> Vec<u8> {
+        point.to_bytes().as_ref().to_vec()
+    }
+
+    fn serialize_g2(point: &Self::G2Affine) -> Vec<u8> {
+        point.to_bytes().as_ref().to_vec()
+    }
+
+    fn prepare_g1(point: Self::G1) -> Self::G1Prepared {
+        point.to_affine()
+    }
+
+    fn prepare_g2(point: Self::G2Affine) -> Self::G2Prepared {
+        point.into()
+    }
+
+    fn multi_miller_loop(
+        g1: Vec<Self::G1Prepared>,
+        g2: Vec<Self::G2Prepared>,
+    ) -> Self::MillerLoopOutput {
+        let terms: Vec<(&Self::G1Affine, &Self::G2Prepared)> = g1.iter().zip(g2.iter()).collect();
+        P::multi_miller_loop(&terms)
+    }
+
+    fn final_exponentiation(miller: Self::MillerLoopOutput) -> Option<Self::TargetField> {
+        Some(miller.final_exponentiation())
+    }
+
+    fn target_one() -> Self::TargetField {
+        // `pairing::Engine::Gt` is only bounded by `group::Group` (written
+        // multiplicatively as `+`/scalar `*`), so its identity stands in
+        // for the target field's multiplicative one.
+        P::Gt::identity()
+    }
+
+    fn target_pow_scalar(base: Self::TargetField, exponent: Self::Scalar) -> Self::TargetField {
+        base * exponent
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bls12_381::{Bls12, G1Affine, G1Projective, G2Affine, G2Projective, Scalar};
+
+    use super::ZkcryptoBackend;
+    use crate::{KeccakTranscript, PairingBatcher};
+
+    /// `e(a, b) = e(c, d)`, i.e. `d = a * b * c^-1`, checked against the
+    /// `bls12_381` crate directly exercising [`ZkcryptoBackend`] end to end -
+    /// the only test in the crate that does not go through `ArkBackend`.
+    #[test]
+    fn verify_accepts_valid_equation_and_rejects_tampered_one() {
+        let a = Scalar::from(7u64);
+        let b = Scalar::from(11u64);
+        let c = Scalar::from(13u64);
+        let d = a * b * c.invert().unwrap();
+
+        let a: G1Affine = (G1Projective::generator() * a).into();
+        let b: G2Affine = (G2Projective::generator() * b).into();
+        let c: G1Affine = (G1Projective::generator() * c).into();
+        let d: G2Affine = (G2Projective::generator() * d).into();
+
+        let mut pairing_batcher = PairingBatcher::<ZkcryptoBackend<Bls12>>::new();
+        pairing_batcher.add_pairing(&[(a, b), (-c, d)]);
+
+        let mut transcript = KeccakTranscript::default();
+        assert!(pairing_batcher.verify(&mut transcript));
+
+        let mut tampered_batcher = PairingBatcher::<ZkcryptoBackend<Bls12>>::new();
+        tampered_batcher.add_pairing(&[(a, b), (-c, -d)]);
+
+        let mut transcript = KeccakTranscript::default();
+        assert!(!tampered_batcher.verify(&mut transcript));
+    }
+}
@@ -0,0 +1,186 @@
+use crate::backend::PairingBackend;
+use crate::transcript::KeccakTranscript;
+use crate::PairingBatcher;
+
+/// Groth16 verifying key, prepared the way `librustzcash` prepares it: the
+/// `alpha`/`beta` pairing is computed once and cached as a target-field
+/// element, and `gamma`/`delta` are stored already negated so per-proof
+/// verification never has to negate a G1 point.
+pub struct PreparedVerifyingKey<B: PairingBackend> {
+    pub alpha_g1_beta_g2: B::TargetField,
+    pub neg_gamma_g2: B::G2Affine,
+    pub neg_delta_g2: B::G2Affine,
+    /// `IC[0]` is the constant term; `IC[1..]` pair one-to-one with the
+    /// public inputs.
+    pub ic: Vec<B::G1Affine>,
+}
+
+/// A Groth16 proof: `(A, B, C)`.
+pub struct Proof<B: PairingBackend> {
+    pub a: B::G1Affine,
+    pub b: B::G2Affine,
+    pub c: B::G1Affine,
+}
+
+/// Batch-verifies many Groth16 proofs against a shared verifying key.
+///
+/// Each proof's check `e(A,B)·e(vk_x,-γ)·e(C,-δ) == α·β` is pushed into a
+/// [`PairingBatcher`] as one equation, so `γ` and `δ` merge across proofs:
+/// batching `N` proofs costs roughly `N + 2` Miller loops instead of `4N`.
+/// The `α·β` constant never enters the Miller loop at all — it is folded
+/// into the expected target by raising it to the sum of each proof's
+/// batching coefficient.
+pub struct Groth16Batcher<B: PairingBackend> {
+    pvk: PreparedVerifyingKey<B>,
+    batcher: PairingBatcher<B>,
+}
+
+impl<B: PairingBackend> Groth16Batcher<B> {
+    pub fn new(pvk: PreparedVerifyingKey<B>) -> Self {
+        Self {
+            pvk,
+            batcher: PairingBatcher::new(),
+        }
+    }
+
+    /// Adds a proof to the batch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `public_inputs.len() != self.pvk.ic.len() - 1`. Without
+    /// this check a mismatched count (e.g. an `IC` built for a different
+    /// circuit) would silently truncate to the shorter of the two via
+    /// `zip`, binding `vk_x` to the wrong statement instead of failing.
+    pub fn add_proof(&mut self, proof: Proof<B>, public_inputs: &[B::Scalar]) {
+        assert_eq!(
+            public_inputs.len(),
+            self.pvk.ic.len() - 1,
+            "expected {} public inputs, got {}",
+            self.pvk.ic.len() - 1,
+            public_inputs.len(),
+        );
+
+        let mut vk_x = B::g1_into_projective(self.pvk.ic[0]);
+        for (ic, input) in self.pvk.ic[1..].iter().zip(public_inputs.iter()) {
+            vk_x += B::g1_mul_scalar(*ic, *input);
+        }
+        let vk_x = B::g1_into_affine(vk_x);
+
+        self.batcher.add_pairing(&[
+            (proof.a, proof.b),
+            (vk_x, self.pvk.neg_gamma_g2),
+            (proof.c, self.pvk.neg_delta_g2),
+        ]);
+    }
+
+    /// Runs the single combined multi-Miller-loop and final exponentiation,
+    /// returning whether every added proof verifies.
+    pub fn verify(&self) -> bool {
+        let exponent = {
+            let mut transcript = KeccakTranscript::default();
+            let r = self.batcher.challenge(&mut transcript);
+
+            let mut running = B::scalar_one();
+            let mut exponent = B::scalar_zero();
+            for _ in 0..self.batcher.len() {
+                B::scalar_add_assign(&mut exponent, running);
+                B::scalar_mul_assign(&mut running, r);
+            }
+            exponent
+        };
+        let target = B::target_pow_scalar(self.pvk.alpha_g1_beta_g2, exponent);
+
+        let mut transcript = KeccakTranscript::default();
+        let (g1, g2) = self.batcher.finalize(&mut transcript);
+        let miller = B::multi_miller_loop(g1, g2);
+
+        match B::final_exponentiation(miller) {
+            Some(result) => result == target,
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ark_bls12_381::{Bls12_381, Fr, G1Projective, G2Projective};
+    use ark_ec::pairing::Pairing;
+    use ark_ec::Group;
+    use ark_ff::Field;
+    use ark_std::{test_rng, UniformRand};
+
+    use super::{Groth16Batcher, PreparedVerifyingKey, Proof};
+    use crate::ArkBackend;
+
+    /// Builds a `PreparedVerifyingKey` and a satisfying witness
+    /// `(a_s, b_s, c_s)` for public input `x`, the same way a real Groth16
+    /// setup/prover would, without a constraint system: pick everything but
+    /// `C` at random and solve for the `C` that makes the check hold.
+    fn setup() -> (PreparedVerifyingKey<ArkBackend<Bls12_381>>, Fr, Fr, Fr, Fr) {
+        let mut rng = test_rng();
+
+        let alpha = Fr::rand(&mut rng);
+        let beta = Fr::rand(&mut rng);
+        let gamma = Fr::rand(&mut rng);
+        let delta = Fr::rand(&mut rng);
+        let ic0 = Fr::rand(&mut rng);
+        let ic1 = Fr::rand(&mut rng);
+
+        let g1 = G1Projective::generator();
+        let g2 = G2Projective::generator();
+
+        let pvk = PreparedVerifyingKey {
+            alpha_g1_beta_g2: Bls12_381::pairing(g1 * alpha, g2 * beta).0,
+            neg_gamma_g2: (g2 * (-gamma)).into(),
+            neg_delta_g2: (g2 * (-delta)).into(),
+            ic: vec![(g1 * ic0).into(), (g1 * ic1).into()],
+        };
+
+        let a_s = Fr::rand(&mut rng);
+        let b_s = Fr::rand(&mut rng);
+        let x = Fr::rand(&mut rng);
+        let vk_x_scalar = ic0 + x * ic1;
+        let c_s = (a_s * b_s - alpha * beta - gamma * vk_x_scalar) * delta.inverse().unwrap();
+
+        (pvk, a_s, b_s, c_s, x)
+    }
+
+    fn proof(a_s: Fr, b_s: Fr, c_s: Fr) -> Proof<ArkBackend<Bls12_381>> {
+        let g1 = G1Projective::generator();
+        let g2 = G2Projective::generator();
+        Proof {
+            a: (g1 * a_s).into(),
+            b: (g2 * b_s).into(),
+            c: (g1 * c_s).into(),
+        }
+    }
+
+    #[test]
+    fn valid_proof_verifies() {
+        let (pvk, a_s, b_s, c_s, x) = setup();
+
+        let mut batcher = Groth16Batcher::<ArkBackend<Bls12_381>>::new(pvk);
+        batcher.add_proof(proof(a_s, b_s, c_s), &[x]);
+
+        assert!(batcher.verify());
+    }
+
+    #[test]
+    fn tampered_proof_fails_to_verify() {
+        let (pvk, a_s, b_s, c_s, x) = setup();
+
+        let mut batcher = Groth16Batcher::<ArkBackend<Bls12_381>>::new(pvk);
+        batcher.add_proof(proof(a_s, b_s, c_s + Fr::from(1u64)), &[x]);
+
+        assert!(!batcher.verify());
+    }
+
+    #[test]
+    #[should_panic(expected = "expected 1 public inputs, got 0")]
+    fn wrong_public_input_count_panics() {
+        let (pvk, a_s, b_s, c_s, _x) = setup();
+
+        let mut batcher = Groth16Batcher::<ArkBackend<Bls12_381>>::new(pvk);
+        batcher.add_proof(proof(a_s, b_s, c_s), &[]);
+    }
+}
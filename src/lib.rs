@@ -1,90 +1,134 @@
-use std::{collections::HashMap, vec};
-
-use ark_ec::pairing::Pairing;
-use ark_ff::One;
-
-pub struct PairingBatcher<E: Pairing> {
-    /// Mapping of all G2 points serialized with correlated G1 points
-    g2_to_g1: HashMap<E::G2, E::G1>,
-    /// challenge
-    challenge: E::ScalarField,
-    /// running challenge
-    running_challenge: E::ScalarField,
+use std::collections::HashMap;
+
+mod backend;
+mod groth16;
+mod transcript;
+
+pub use backend::{ArkBackend, PairingBackend, PreparedCache, ZkcryptoBackend};
+pub use groth16::{Groth16Batcher, PreparedVerifyingKey, Proof};
+pub use transcript::{KeccakTranscript, Transcript};
+
+/// Batches many pairing equations `e(a_0, b_0) * e(a_1, b_1) * ... == 1` into
+/// a single multi-Miller-loop call, generic over the pairing engine via
+/// [`PairingBackend`].
+///
+/// Each equation added via [`add_pairing`](Self::add_pairing) is buffered and
+/// assigned an independent coefficient `r^i` (`i` being the equation's index,
+/// `r^0 = 1`), so a product of Miller loops equal to identity implies every
+/// equation holds individually. The scalar `r` is only squeezed from a
+/// [`Transcript`] in [`finalize`](Self::finalize), after absorbing every
+/// point that was added, which binds it to the equations being checked.
+pub struct PairingBatcher<B: PairingBackend> {
+    /// Equations added so far, each a list of (G1, G2) pairs that must
+    /// multiply to one. `pub(crate)` so backend-specific extensions (e.g.
+    /// the BLS12 `PreparedCache` integration) can fold over it directly.
+    pub(crate) equations: Vec<Vec<(B::G1Affine, B::G2Affine)>>,
 }
 
+impl<B: PairingBackend> Default for PairingBatcher<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-impl<E: Pairing> PairingBatcher<E> {
-    pub fn new(challenge: E::ScalarField) -> Self {
-        Self {
-            g2_to_g1: HashMap::default(),
-            challenge,
-            running_challenge: E::ScalarField::one(),
-        }
+impl<B: PairingBackend> PairingBatcher<B> {
+    pub fn new() -> Self {
+        Self { equations: vec![] }
     }
 
-    /// Adds new pairing equation that needs to be checked
-    pub fn add_pairing(&mut self, pairs: &[(E::G1Affine, E::G2Affine)]) {
-        let g2_points: Vec<E::G2> = pairs.iter().map(|&(_, g2)| g2.into()).collect();
+    /// Adds a new pairing equation that needs to be checked.
+    pub fn add_pairing(&mut self, pairs: &[(B::G1Affine, B::G2Affine)]) {
+        self.equations.push(pairs.to_vec());
+    }
 
-        let mut is_present: bool = false;
-        for g2 in g2_points.iter() {
-            if self.g2_to_g1.get(g2).is_some() {
-                is_present = true;
-                break;
+    /// Number of equations added so far, i.e. the number of distinct
+    /// `r^i` coefficients `finalize` will assign.
+    pub(crate) fn len(&self) -> usize {
+        self.equations.len()
+    }
+
+    /// Absorbs every point added so far into `transcript` and squeezes the
+    /// combination challenge `r`. Calling this does not consume the
+    /// batcher: a fresh transcript of the same kind squeezes the same `r`,
+    /// since it is a pure function of the absorbed equations.
+    pub(crate) fn challenge<T: Transcript<B>>(&self, transcript: &mut T) -> B::Scalar {
+        for equation in &self.equations {
+            for (g1, g2) in equation {
+                transcript.absorb_g1(g1);
+                transcript.absorb_g2(g2);
             }
         }
-
-        let g1_points: Vec<E::G1> = if is_present {
-            self.running_challenge *= self.challenge;
-            pairs
-                .iter()
-                .map(|&(g1, _)| g1 * self.running_challenge)
-                .collect()
-        } else {
-            pairs.iter().map(|pair| pair.0.into()).collect()
-        };
-
-        self.update_mapping(&g2_points, &g1_points);
+        transcript.squeeze_scalar()
     }
 
-    /// Updates mapping based on pairs that are added
-    fn update_mapping(&mut self, g2_points: &[E::G2], g1_points: &[E::G1]) {
-        g2_points.iter().zip(
-        g1_points
-            .iter())
-            .for_each(|(&g2, g1)| {
-                self.g2_to_g1
-                    .entry(g2)
-                    .and_modify(|g1_point| *g1_point += g1)
-                    .or_insert(*g1);
-            });
-    }
+    /// Squeezes the combination challenge from `transcript`, folds
+    /// `r^i * (g1 points of equation i)` into a `g2 -> g1` mapping, and
+    /// returns the vectors ready to be passed to `multi_miller_loop`.
+    pub fn finalize<T: Transcript<B>>(
+        &self,
+        transcript: &mut T,
+    ) -> (Vec<B::G1Prepared>, Vec<B::G2Prepared>) {
+        let r = self.challenge(transcript);
+
+        // Keyed by the serialized point rather than `B::G2Affine` itself,
+        // since not every backend's affine point type is hashable (e.g.
+        // `bls12_381::G2Affine` is `Eq` but not `Hash`).
+        let mut g2_to_g1: HashMap<Vec<u8>, (B::G2Affine, B::G1)> = HashMap::default();
+        let mut running_challenge = B::scalar_one();
+        for equation in &self.equations {
+            for &(g1, g2) in equation {
+                let g1 = B::g1_mul_scalar(g1, running_challenge);
+                g2_to_g1
+                    .entry(B::serialize_g2(&g2))
+                    .and_modify(|(_, acc)| *acc += g1)
+                    .or_insert((g2, g1));
+            }
+            B::scalar_mul_assign(&mut running_challenge, r);
+        }
 
-    /// Returns output that is ready to be called on MultiMillerLoop
-    pub fn finalize(&self) -> (Vec<E::G1Prepared>, Vec<E::G2Prepared>) {
-        let mut g1_prepared_points = vec![]; 
+        let mut g1_prepared_points = vec![];
         let mut g2_prepared_points = vec![];
-        self.g2_to_g1
-            .iter()
-            .for_each(|(g2, g1)| {
-                g1_prepared_points.push(g1.into());
-                g2_prepared_points.push(g2.into());
-            });
+        g2_to_g1.into_values().for_each(|(g2, g1)| {
+            g1_prepared_points.push(B::prepare_g1(g1));
+            g2_prepared_points.push(B::prepare_g2(g2));
+        });
 
         (g1_prepared_points, g2_prepared_points)
     }
+
+    /// Like [`finalize`](Self::finalize), but also runs the multi-Miller
+    /// loop, returning the raw `MillerLoopOutput`/`TargetField` instead of
+    /// the prepared point vectors. Keeps the (cheap to share) intermediate
+    /// Miller result available so callers can fold in additional off-circuit
+    /// pairing terms (e.g. Groth16's precomputed `alpha * beta` constant)
+    /// before running final exponentiation themselves.
+    pub fn finalize_miller<T: Transcript<B>>(&self, transcript: &mut T) -> B::MillerLoopOutput {
+        let (g1, g2) = self.finalize(transcript);
+        B::multi_miller_loop(g1, g2)
+    }
+
+    /// Runs the full pipeline - finalize, multi-Miller loop, final
+    /// exponentiation - and checks the result against the target group
+    /// identity.
+    pub fn verify<T: Transcript<B>>(&self, transcript: &mut T) -> bool {
+        let miller = self.finalize_miller(transcript);
+        match B::final_exponentiation(miller) {
+            Some(result) => result == B::target_one(),
+            None => false,
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use ark_ec::{bls12::{self, G1Prepared, G2Prepared, Bls12, Bls12Config}, Group, pairing::Pairing};
+    use ark_ec::pairing::Pairing;
+    use ark_ec::Group;
     use ark_std::{test_rng, UniformRand, One};
     use ark_bls12_381::{Fr, G1Affine, G1Projective, G2Affine, G2Projective, Fq12, Bls12_381};
     use ark_ff::Field;
-    use ark_ec::pairing::{prepare_g1, prepare_g2};
     use std::ops::Neg;
 
-    use crate::PairingBatcher;
+    use crate::{ArkBackend, KeccakTranscript, PairingBatcher};
 
     #[test]
     fn test() {
@@ -131,23 +175,14 @@ mod test {
 
         {
             // Batched test
-            let mut pairing_batcher = PairingBatcher::<Bls12_381>::new(Fr::rand(&mut rng));
+            let mut pairing_batcher = PairingBatcher::<ArkBackend<Bls12_381>>::new();
 
             pairing_batcher.add_pairing(&[(a, b), ((-c), d)]);
             pairing_batcher.add_pairing(&[(j, b), ((-f), g)]);
             pairing_batcher.add_pairing(&[(e, d), ((-h), b)]);
 
-            let batched_tuples = pairing_batcher.finalize();
-            /*
-                e(a, b) = e(c, d)
-                e(j, b) = e(f, g)
-                e(e, d) = e(h, b)
-
-                ==>
-
-                e(a + [R]j + [R^2]h, b).e(c + [R^2]e, d).e([R]f, g)
-            */
-            assert_eq!(3, batched_tuples.0.len());
+            let mut transcript = KeccakTranscript::default();
+            let batched_tuples = pairing_batcher.finalize(&mut transcript);
 
             let mlo = {
                 Bls12_381::multi_miller_loop(
@@ -157,6 +192,9 @@ mod test {
             };
             let pairing_result =  Bls12_381::final_exponentiation(mlo);
             assert_eq!(pairing_result.unwrap().0, Fq12::one());
+
+            let mut transcript = KeccakTranscript::default();
+            assert!(pairing_batcher.verify(&mut transcript));
         }
     }
 }
@@ -0,0 +1,37 @@
+use sha3::{Digest, Keccak256};
+
+use crate::backend::PairingBackend;
+
+/// Binds the batching challenge to every point that was actually added to a
+/// `PairingBatcher`, so the combination cannot be fixed before the equations
+/// are known.
+pub trait Transcript<B: PairingBackend> {
+    /// Absorbs the compressed serialization of a G1 point.
+    fn absorb_g1(&mut self, point: &B::G1Affine);
+    /// Absorbs the compressed serialization of a G2 point.
+    fn absorb_g2(&mut self, point: &B::G2Affine);
+    /// Squeezes the next scalar out of the transcript.
+    fn squeeze_scalar(&mut self) -> B::Scalar;
+}
+
+/// Default transcript: absorbs points into a running Keccak256 digest and
+/// derives the scalar from it.
+#[derive(Default)]
+pub struct KeccakTranscript {
+    hasher: Keccak256,
+}
+
+impl<B: PairingBackend> Transcript<B> for KeccakTranscript {
+    fn absorb_g1(&mut self, point: &B::G1Affine) {
+        self.hasher.update(B::serialize_g1(point));
+    }
+
+    fn absorb_g2(&mut self, point: &B::G2Affine) {
+        self.hasher.update(B::serialize_g2(point));
+    }
+
+    fn squeeze_scalar(&mut self) -> B::Scalar {
+        let digest = self.hasher.finalize_reset();
+        B::scalar_from_bytes(&digest)
+    }
+}